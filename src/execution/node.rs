@@ -135,4 +135,4 @@ impl From<Vec<Node>> for Node {
             _ => Node::Expr(n),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,143 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Config, Context as LineContext, Editor, Helper};
+
+use templar::{Context, Templar};
+
+/// Operator tokens highlighted as-typed, mirrored from the
+/// `Operations` variants in `templar::execution::operation`.
+const OPERATORS: &[&str] = &[
+    "+", "-", "*", "/", "%", "+=", "-=", "*=", "/=", "%=", "==", "!=", ">", "<", ">=", "<=", "&&",
+    "||",
+];
+
+/// Names of the registered filters/functions a user can reach for after a
+/// `|` or at the start of an identifier, e.g. `sin`, `pow`, `sqrt`, sourced
+/// straight from the builtin registry so this list can't drift out of sync.
+fn known_names() -> BTreeSet<&'static str> {
+    Templar::builtin_function_names().into_iter().collect()
+}
+
+struct TemplarHelper {
+    names: BTreeSet<&'static str>,
+}
+
+impl Validator for TemplarHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        let input = ctx.input();
+        if unbalanced(input, "{{", "}}") || unbalanced(input, "{%", "%}") {
+            return Ok(ValidationResult::Incomplete);
+        }
+        Ok(ValidationResult::Valid(None))
+    }
+}
+
+/// Counts open/close delimiter pairs and reports whether the input still has
+/// an unclosed `open` waiting for its matching `close`.
+fn unbalanced(input: &str, open: &str, close: &str) -> bool {
+    input.matches(open).count() > input.matches(close).count()
+}
+
+impl Highlighter for TemplarHelper {
+    fn highlight<'l>(&self, line: &'l str, _pos: usize) -> Cow<'l, str> {
+        let mut highlighted = String::with_capacity(line.len());
+        for word in line.split_inclusive(char::is_whitespace) {
+            let trimmed = word.trim_end();
+            if OPERATORS.contains(&trimmed) {
+                highlighted.push_str("\x1b[33m");
+                highlighted.push_str(trimmed);
+                highlighted.push_str("\x1b[0m");
+            } else if trimmed.starts_with('"') || trimmed.starts_with('`') {
+                highlighted.push_str("\x1b[32m");
+                highlighted.push_str(trimmed);
+                highlighted.push_str("\x1b[0m");
+            } else if self.names.contains(trimmed) {
+                highlighted.push_str("\x1b[36m");
+                highlighted.push_str(trimmed);
+                highlighted.push_str("\x1b[0m");
+            } else {
+                highlighted.push_str(trimmed);
+            }
+            highlighted.push_str(&word[trimmed.len()..]);
+        }
+        Cow::Owned(highlighted)
+    }
+
+    fn highlight_char(&self, _line: &str, _pos: usize) -> bool {
+        true
+    }
+}
+
+impl Hinter for TemplarHelper {
+    type Hint = String;
+}
+
+impl Completer for TemplarHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &LineContext<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let start = line[..pos]
+            .rfind(|c: char| !c.is_alphanumeric() && c != '_')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let prefix = &line[start..pos];
+        let candidates = self
+            .names
+            .iter()
+            .filter(|name| name.starts_with(prefix))
+            .map(|name| Pair {
+                display: name.to_string(),
+                replacement: name.to_string(),
+            })
+            .collect();
+        Ok((start, candidates))
+    }
+}
+
+impl Helper for TemplarHelper {}
+
+fn main() -> rustyline::Result<()> {
+    let templar = Templar::global();
+    let context = Context::new_standard(unstructured::Document::Null);
+
+    let config = Config::builder().auto_add_history(true).build();
+    let mut editor = Editor::with_config(config)?;
+    editor.set_helper(Some(TemplarHelper {
+        names: known_names(),
+    }));
+
+    println!("templar REPL — enter an expression, Ctrl-D to exit");
+    loop {
+        match editor.readline("templar> ") {
+            Ok(line) => {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                match templar.parse_template(&line) {
+                    Ok(template) => match template.render(&context) {
+                        Ok(rendered) => println!("{}", rendered),
+                        Err(e) => eprintln!("render error: {}", e),
+                    },
+                    Err(e) => eprintln!("parse error: {}", e),
+                }
+            }
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(e) => {
+                eprintln!("readline error: {}", e);
+                break;
+            }
+        }
+    }
+    Ok(())
+}
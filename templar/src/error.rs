@@ -0,0 +1,32 @@
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, TemplarError>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemplarError {
+    RenderFailure(String),
+    TypeMismatch {
+        operator: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for TemplarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplarError::RenderFailure(msg) => write!(f, "{}", msg),
+            TemplarError::TypeMismatch {
+                operator,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "operator '{}' expected {} but got {}",
+                operator, expected, actual
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TemplarError {}
@@ -118,6 +118,13 @@ impl From<FilterExecutor> for Executors {
     }
 }
 
+impl From<FunctionExecutor> for Executors {
+    #[inline]
+    fn from(t: FunctionExecutor) -> Self {
+        Self::Function(t)
+    }
+}
+
 impl From<PipedExecutor> for Executors {
     #[inline]
     fn from(t: PipedExecutor) -> Self {
@@ -1,5 +1,24 @@
 use super::*;
 
+mod math;
+
+/// The named `Function`/`Filter` entries templates can reach without the
+/// user registering anything themselves (e.g. `{{ sqrt(x) }}`, `{{ a | pow(b) }}`),
+/// keyed by the name they're looked up with. The parser's symbol table
+/// should seed itself from this alongside any user-registered filters.
+pub(crate) fn builtin_functions() -> std::collections::HashMap<&'static str, Executors> {
+    math::executors().into_iter().collect()
+}
+
+impl Templar {
+    /// Names of the builtin functions/filters (e.g. `sin`, `pow`, `sqrt`)
+    /// so callers like the REPL can build completions from the real
+    /// registry instead of maintaining their own copy of this list.
+    pub fn builtin_function_names() -> Vec<&'static str> {
+        builtin_functions().into_keys().collect()
+    }
+}
+
 pub struct Operation {
     oper: Executors,
     name: String,
@@ -84,9 +103,19 @@ map_operations! {
     PipedExecutor: GreaterThanEquals:greater_than_equals;
     PipedExecutor: LessThanEquals:less_than_equals;
     PipedExecutor: Set:set;
+    PipedExecutor: AddAssign:add_assign;
+    PipedExecutor: SubAssign:sub_assign;
+    PipedExecutor: MulAssign:mul_assign;
+    PipedExecutor: DivAssign:div_assign;
+    PipedExecutor: ModAssign:mod_assign;
     ConditionalExecutor: IfThen:if_then;
     IndeterminateExecutor: Concat:concat;
+    IndeterminateExecutor: Sum:sum;
+    IndeterminateExecutor: Product:product;
+    IndeterminateExecutor: SeqMin:seq_min;
+    IndeterminateExecutor: SeqMax:seq_max;
     LoopExecutor: ForLoop:for_loop;
+    LoopExecutor: Reduce:reduce;
 }
 
 macro_rules! simple_pipe {
@@ -102,25 +131,168 @@ macro_rules! simple_pipe {
 }
 
 macro_rules! number {
-    ($doc:ident) => {
-        match $doc.into_inner().cast::<i64>() {
+    ($doc:ident, $operator:expr) => {{
+        let inner = $doc.into_inner();
+        match inner.clone().cast::<i64>() {
             Some(i) => i,
             None => {
-                return TemplarError::RenderFailure("Math operations require numeric types".into())
-                    .into()
+                return TemplarError::TypeMismatch {
+                    operator: $operator.into(),
+                    expected: "Number".into(),
+                    actual: format!("{:?}", inner),
+                }
+                .into()
             }
         }
+    }};
+}
+
+/// A number coerced out of a `Data` value, keeping floats as floats instead of
+/// truncating them the way the old `i64`-only `number!` macro did.
+pub(crate) enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+impl Number {
+    pub(crate) fn coerce(operator: &'static str, data: Data) -> std::result::Result<Number, Data> {
+        let inner = data.into_inner();
+        match inner {
+            InnerData::Float(f) => Ok(Number::Float(f)),
+            other => match other.clone().cast::<i64>() {
+                Some(i) => Ok(Number::Int(i)),
+                None => Err(TemplarError::TypeMismatch {
+                    operator: operator.into(),
+                    expected: "Number".into(),
+                    actual: format!("{:?}", other),
+                }
+                .into()),
+            },
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> f64 {
+        match self {
+            Number::Int(i) => *i as f64,
+            Number::Float(f) => *f,
+        }
+    }
+
+    pub(crate) fn into_data(self) -> Data {
+        match self {
+            Number::Int(i) => Data::from(i),
+            Number::Float(f) => Data::from(f),
+        }
+    }
+}
+
+macro_rules! arithmetic {
+    ( $( $fn_name:ident : $operator:expr => ( $int_op:tt , $float_op:tt ) ; )* ) => {
+        $(
+            fn $fn_name(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+                let l = match Number::coerce($operator, data_unwrap!(left.exec(ctx))) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                let r = match Number::coerce($operator, data_unwrap!(right.exec(ctx))) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                match (l, r) {
+                    (Number::Int(a), Number::Int(b)) => Data::from(a $int_op b),
+                    (Number::Float(a), Number::Int(b)) => Data::from(a $float_op (b as f64)),
+                    (Number::Int(a), Number::Float(b)) => Data::from((a as f64) $float_op b),
+                    (Number::Float(a), Number::Float(b)) => Data::from(a $float_op b),
+                }
+            }
+        )*
     };
 }
 
+arithmetic! {
+    add: "+" => (+, +);
+    subtract: "-" => (-, -);
+    multiply: "*" => (*, *);
+}
+
+fn divide(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let l = match Number::coerce("/", data_unwrap!(left.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let r = match Number::coerce("/", data_unwrap!(right.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    match (l, r) {
+        (_, Number::Int(0)) => {
+            TemplarError::RenderFailure("Attempted to divide by zero".into()).into()
+        }
+        (Number::Int(a), Number::Int(b)) if a % b == 0 => Data::from(a / b),
+        (Number::Int(a), Number::Int(b)) => Data::from(a as f64 / b as f64),
+        (Number::Float(a), Number::Int(b)) => Data::from(a / b as f64),
+        (Number::Int(a), Number::Float(b)) => Data::from(a as f64 / b),
+        (Number::Float(a), Number::Float(b)) => Data::from(a / b),
+    }
+}
+
+fn modulus(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let l = match Number::coerce("%", data_unwrap!(left.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let r = match Number::coerce("%", data_unwrap!(right.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    match (l, r) {
+        (_, Number::Int(0)) => {
+            TemplarError::RenderFailure("Attempted to divide by zero".into()).into()
+        }
+        (Number::Int(a), Number::Int(b)) => Data::from(a % b),
+        (Number::Float(a), Number::Int(b)) => Data::from(a % b as f64),
+        (Number::Int(a), Number::Float(b)) => Data::from(a as f64 % b),
+        (Number::Float(a), Number::Float(b)) => Data::from(a % b),
+    }
+}
+
+// `and`/`or` are hand-written rather than generated by `simple_pipe!` so the
+// right-hand `Node` is only executed when its value can actually change the
+// result, matching short-circuit boolean semantics instead of always
+// evaluating both sides.
+fn and(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let l = data_unwrap!(left.exec(ctx))
+        .into_inner()
+        .cast::<bool>()
+        .unwrap_or_default();
+    if !l {
+        return Data::from(false);
+    }
+    Data::from(
+        data_unwrap!(right.exec(ctx))
+            .into_inner()
+            .cast::<bool>()
+            .unwrap_or_default(),
+    )
+}
+
+fn or(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let l = data_unwrap!(left.exec(ctx))
+        .into_inner()
+        .cast::<bool>()
+        .unwrap_or_default();
+    if l {
+        return Data::from(true);
+    }
+    Data::from(
+        data_unwrap!(right.exec(ctx))
+            .into_inner()
+            .cast::<bool>()
+            .unwrap_or_default(),
+    )
+}
+
 simple_pipe! {
-    add (l, r) -> { number!(l) + number!(r) };
-    subtract(l, r) -> { number!(l) - number!(r) };
-    divide(l, r) -> { number!(l) / number!(r) };
-    multiply(l, r) -> { number!(l) * number!(r) };
-    modulus(l, r) -> { number!(l) % number!(r) };
-    and(l, r) -> { l.into_inner().cast::<bool>().unwrap_or_default() && r.into_inner().cast::<bool>().unwrap_or_default() };
-    or(l, r) -> { l.into_inner().cast::<bool>().unwrap_or_default() || r.into_inner().cast::<bool>().unwrap_or_default() };
     equals(l, r) -> { l.into_inner() == r.into_inner() };
     not_equals(l, r) -> { l.into_inner() != r.into_inner() };
     greater_than(l, r) -> { l.into_inner() > r.into_inner() };
@@ -135,7 +307,12 @@ fn if_then(ctx: &ContextWrapper, cnd: &Node, p: &Node, n: &Node) -> Data {
         InnerData::Bool(true) => p.exec(ctx),
         InnerData::Bool(false) => n.exec(ctx),
         InnerData::Err(e) => Data::new(InnerData::Err(e)),
-        _ => TemplarError::RenderFailure("If condition must evaluate to boolean!".into()).into(),
+        other => TemplarError::TypeMismatch {
+            operator: "if".into(),
+            expected: "Boolean".into(),
+            actual: format!("{:?}", other),
+        }
+        .into(),
     }
 }
 
@@ -212,6 +389,172 @@ fn for_loop(ctx: &ContextWrapper, val_name: &Node, array_path: &Node, exec: &Nod
     }
 }
 
+/// Walks an `InnerData::Seq` produced by `input[0]` and folds it into a
+/// single `Number`, without rendering any intermediate template strings the
+/// way `for_loop` does.
+fn fold_seq(
+    ctx: &ContextWrapper,
+    input: &[Node],
+    operator: &'static str,
+    init: Number,
+    combine: fn(Number, Number) -> Number,
+) -> Data {
+    if input.len() != 1 {
+        return TemplarError::RenderFailure(format!("'{}' requires exactly one argument", operator))
+            .into();
+    }
+    let array_exec = input[0].exec(ctx).into_result();
+    if let Err(e) = array_exec {
+        return e.into();
+    }
+    match array_exec.unwrap().into_inner() {
+        InnerData::Seq(items) => {
+            let mut acc = init;
+            for item in items {
+                let n = match Number::coerce(operator, Data::new(item)) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                acc = combine(acc, n);
+            }
+            acc.into_data()
+        }
+        other => TemplarError::TypeMismatch {
+            operator: operator.into(),
+            expected: "Sequence".into(),
+            actual: format!("{:?}", other),
+        }
+        .into(),
+    }
+}
+
+fn sum(ctx: &ContextWrapper, input: &[Node]) -> Data {
+    fold_seq(ctx, input, "sum", Number::Int(0), |acc, n| match (acc, n) {
+        (Number::Int(a), Number::Int(b)) => Number::Int(a + b),
+        (Number::Float(a), Number::Int(b)) => Number::Float(a + b as f64),
+        (Number::Int(a), Number::Float(b)) => Number::Float(a as f64 + b),
+        (Number::Float(a), Number::Float(b)) => Number::Float(a + b),
+    })
+}
+
+fn product(ctx: &ContextWrapper, input: &[Node]) -> Data {
+    fold_seq(ctx, input, "product", Number::Int(1), |acc, n| {
+        match (acc, n) {
+            (Number::Int(a), Number::Int(b)) => Number::Int(a * b),
+            (Number::Float(a), Number::Int(b)) => Number::Float(a * b as f64),
+            (Number::Int(a), Number::Float(b)) => Number::Float(a as f64 * b),
+            (Number::Float(a), Number::Float(b)) => Number::Float(a * b),
+        }
+    })
+}
+
+/// Shared by `seq_min`/`seq_max`: there's no sane identity element for an
+/// empty sequence, so the accumulator starts at the first item instead of a
+/// fixed `init` like `fold_seq` uses.
+fn extreme_seq(
+    ctx: &ContextWrapper,
+    input: &[Node],
+    operator: &'static str,
+    pick: fn(Number, Number) -> Number,
+) -> Data {
+    if input.len() != 1 {
+        return TemplarError::RenderFailure(format!("'{}' requires exactly one argument", operator))
+            .into();
+    }
+    let array_exec = input[0].exec(ctx).into_result();
+    if let Err(e) = array_exec {
+        return e.into();
+    }
+    match array_exec.unwrap().into_inner() {
+        InnerData::Seq(items) => {
+            let mut acc: Option<Number> = None;
+            for item in items {
+                let n = match Number::coerce(operator, Data::new(item)) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                acc = Some(match acc {
+                    Some(current) => pick(current, n),
+                    None => n,
+                });
+            }
+            match acc {
+                Some(n) => n.into_data(),
+                None => TemplarError::RenderFailure(format!(
+                    "'{}' requires a non-empty sequence",
+                    operator
+                ))
+                .into(),
+            }
+        }
+        other => TemplarError::TypeMismatch {
+            operator: operator.into(),
+            expected: "Sequence".into(),
+            actual: format!("{:?}", other),
+        }
+        .into(),
+    }
+}
+
+fn seq_min(ctx: &ContextWrapper, input: &[Node]) -> Data {
+    extreme_seq(ctx, input, "seq_min", |a, b| {
+        if a.as_f64() <= b.as_f64() {
+            a
+        } else {
+            b
+        }
+    })
+}
+
+fn seq_max(ctx: &ContextWrapper, input: &[Node]) -> Data {
+    extreme_seq(ctx, input, "seq_max", |a, b| {
+        if a.as_f64() >= b.as_f64() {
+            a
+        } else {
+            b
+        }
+    })
+}
+
+/// `reduce(initial, array, body)`: folds `array` into a single value,
+/// exposing the running accumulator and current item to `body` as the
+/// scoped `acc`/`item` context values, reusing the same `set_path` mechanism
+/// `for_loop` uses for its scope-local value.
+fn reduce(ctx: &ContextWrapper, initial: &Node, array_path: &Node, body: &Node) -> Data {
+    let array_exec = array_path.exec(ctx).into_result();
+    if let Err(e) = array_exec {
+        return e.into();
+    }
+    let items = match array_exec.unwrap().into_inner() {
+        InnerData::Seq(items) => items,
+        other => {
+            return TemplarError::TypeMismatch {
+                operator: "reduce".into(),
+                expected: "Sequence".into(),
+                actual: format!("{:?}", other),
+            }
+            .into()
+        }
+    };
+
+    let acc_path = vec!["acc".to_string()];
+    let item_path = vec!["item".to_string()];
+    let acc_refs: Vec<&InnerData> = acc_path.iter().collect();
+    let item_refs: Vec<&InnerData> = item_path.iter().collect();
+
+    let mut acc = data_unwrap!(initial.exec(ctx));
+    for item in items {
+        if let Err(e) = ctx.set_path(&acc_refs, acc.into_inner()) {
+            return e.into();
+        }
+        if let Err(e) = ctx.set_path(&item_refs, item) {
+            return e.into();
+        }
+        acc = data_unwrap!(body.exec(ctx));
+    }
+    acc
+}
+
 fn set(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
     let val = right.exec(ctx).into_result();
     match (left, val) {
@@ -230,3 +573,108 @@ fn set(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
         }
     }
 }
+
+macro_rules! compound_assign {
+    ( $( $fn_name:ident : $operator:expr => ( $int_op:tt , $float_op:tt ) ; )* ) => {
+        $(
+            fn $fn_name(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+                let path = match left {
+                    Node::Value(path) => path,
+                    _ => {
+                        return TemplarError::RenderFailure(
+                            "Compound assignment target must be a value path".into(),
+                        )
+                        .into()
+                    }
+                };
+                let path_refs: Vec<&String> = path.iter().collect();
+                let cur = match Number::coerce($operator, Data::from(ctx.get_path(&path_refs))) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                let rhs = match Number::coerce($operator, data_unwrap!(right.exec(ctx))) {
+                    Ok(n) => n,
+                    Err(e) => return e,
+                };
+                let result = match (cur, rhs) {
+                    (Number::Int(a), Number::Int(b)) => InnerData::from(a $int_op b),
+                    (Number::Float(a), Number::Int(b)) => InnerData::from(a $float_op (b as f64)),
+                    (Number::Int(a), Number::Float(b)) => InnerData::from((a as f64) $float_op b),
+                    (Number::Float(a), Number::Float(b)) => InnerData::from(a $float_op b),
+                };
+                let ref_vec: Vec<&InnerData> = path.iter().collect();
+                Data::check(ctx.set_path(&ref_vec, result))
+            }
+        )*
+    };
+}
+
+compound_assign! {
+    add_assign: "+=" => (+, +);
+    sub_assign: "-=" => (-, -);
+    mul_assign: "*=" => (*, *);
+}
+
+fn div_assign(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let path = match left {
+        Node::Value(path) => path,
+        _ => {
+            return TemplarError::RenderFailure(
+                "Compound assignment target must be a value path".into(),
+            )
+            .into()
+        }
+    };
+    let path_refs: Vec<&String> = path.iter().collect();
+    let cur = match Number::coerce("/=", Data::from(ctx.get_path(&path_refs))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let rhs = match Number::coerce("/=", data_unwrap!(right.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let result = match (cur, rhs) {
+        (_, Number::Int(0)) => {
+            return TemplarError::RenderFailure("Attempted to divide by zero".into()).into()
+        }
+        (Number::Int(a), Number::Int(b)) => InnerData::from(a / b),
+        (Number::Float(a), Number::Int(b)) => InnerData::from(a / b as f64),
+        (Number::Int(a), Number::Float(b)) => InnerData::from(a as f64 / b),
+        (Number::Float(a), Number::Float(b)) => InnerData::from(a / b),
+    };
+    let ref_vec: Vec<&InnerData> = path.iter().collect();
+    Data::check(ctx.set_path(&ref_vec, result))
+}
+
+fn mod_assign(ctx: &ContextWrapper, left: &Node, right: &Node) -> Data {
+    let path = match left {
+        Node::Value(path) => path,
+        _ => {
+            return TemplarError::RenderFailure(
+                "Compound assignment target must be a value path".into(),
+            )
+            .into()
+        }
+    };
+    let path_refs: Vec<&String> = path.iter().collect();
+    let cur = match Number::coerce("%=", Data::from(ctx.get_path(&path_refs))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let rhs = match Number::coerce("%=", data_unwrap!(right.exec(ctx))) {
+        Ok(n) => n,
+        Err(e) => return e,
+    };
+    let result = match (cur, rhs) {
+        (_, Number::Int(0)) => {
+            return TemplarError::RenderFailure("Attempted to divide by zero".into()).into()
+        }
+        (Number::Int(a), Number::Int(b)) => InnerData::from(a % b),
+        (Number::Float(a), Number::Int(b)) => InnerData::from(a % b as f64),
+        (Number::Int(a), Number::Float(b)) => InnerData::from(a as f64 % b),
+        (Number::Float(a), Number::Float(b)) => InnerData::from(a % b),
+    };
+    let ref_vec: Vec<&InnerData> = path.iter().collect();
+    Data::check(ctx.set_path(&ref_vec, result))
+}
@@ -0,0 +1,78 @@
+use super::*;
+
+fn numeric_arg(operator: &'static str, data: Data) -> std::result::Result<f64, Data> {
+    let inner = data.into_inner();
+    match inner.clone().cast::<f64>() {
+        Some(f) => Ok(f),
+        None => Err(TemplarError::TypeMismatch {
+            operator: operator.into(),
+            expected: "Number".into(),
+            actual: format!("{:?}", inner),
+        }
+        .into()),
+    }
+}
+
+macro_rules! unary_math {
+    ( $( $name:ident ( $v:ident ) -> { $( $tail:tt )* } ; )* ) => {
+        $(
+            fn $name(value: Data) -> Data {
+                match numeric_arg(stringify!($name), value) {
+                    Ok($v) => Data::from( $( $tail )* ),
+                    Err(e) => e,
+                }
+            }
+        )*
+    };
+}
+
+unary_math! {
+    sin(v) -> { v.sin() };
+    cos(v) -> { v.cos() };
+    sqrt(v) -> { v.sqrt() };
+    abs(v) -> { v.abs() };
+    floor(v) -> { v.floor() };
+    ceil(v) -> { v.ceil() };
+    round(v) -> { v.round() };
+}
+
+macro_rules! binary_math {
+    ( $( $name:ident ( $l:ident , $r:ident ) -> { $( $tail:tt )* } ; )* ) => {
+        $(
+            fn $name(left: Data, right: Data) -> Data {
+                let $l = match numeric_arg(stringify!($name), left) {
+                    Ok(f) => f,
+                    Err(e) => return e,
+                };
+                let $r = match numeric_arg(stringify!($name), right) {
+                    Ok(f) => f,
+                    Err(e) => return e,
+                };
+                Data::from( $( $tail )* )
+            }
+        )*
+    };
+}
+
+binary_math! {
+    pow(l, r) -> { l.powf(r) };
+    min(l, r) -> { l.min(r) };
+    max(l, r) -> { l.max(r) };
+}
+
+/// The math functions/filters this module contributes to the builtin
+/// `Function`/`Filter` registry, keyed by the name templates call them with.
+pub(crate) fn executors() -> Vec<(&'static str, Executors)> {
+    vec![
+        ("sin", FunctionExecutor::new(Arc::new(sin)).into()),
+        ("cos", FunctionExecutor::new(Arc::new(cos)).into()),
+        ("sqrt", FunctionExecutor::new(Arc::new(sqrt)).into()),
+        ("abs", FunctionExecutor::new(Arc::new(abs)).into()),
+        ("floor", FunctionExecutor::new(Arc::new(floor)).into()),
+        ("ceil", FunctionExecutor::new(Arc::new(ceil)).into()),
+        ("round", FunctionExecutor::new(Arc::new(round)).into()),
+        ("pow", FilterExecutor::new(Arc::new(pow)).into()),
+        ("min", FilterExecutor::new(Arc::new(min)).into()),
+        ("max", FilterExecutor::new(Arc::new(max)).into()),
+    ]
+}
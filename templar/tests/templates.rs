@@ -0,0 +1,105 @@
+use templar::error::*;
+use templar::{Context, Templar};
+
+fn render(source: &str) -> Result<String> {
+    let context = Context::new_standard(unstructured::Document::Null);
+    Templar::global().parse_template(source)?.render(&context)
+}
+
+#[test]
+fn compound_assign_adds_in_place() {
+    let rendered = render("{{ total = 10 }}{{ total += 5 }}{{ total }}").unwrap();
+    assert_eq!(rendered, "15");
+}
+
+#[test]
+fn compound_assign_requires_a_numeric_target() {
+    let err = render("{{ name = \"bob\" }}{{ name += 1 }}").unwrap_err();
+    assert!(matches!(err, TemplarError::TypeMismatch { .. }));
+}
+
+#[test]
+fn arithmetic_type_mismatch_names_the_operator_and_types() {
+    let err = render("{{ 1 + \"two\" }}").unwrap_err();
+    assert!(matches!(
+        err,
+        TemplarError::TypeMismatch { ref operator, ref expected, .. }
+            if operator == "+" && expected == "Number"
+    ));
+    assert!(err.to_string().starts_with("operator '+' expected Number but got"));
+}
+
+#[test]
+fn if_condition_type_mismatch_reports_boolean_expected() {
+    let err = render("{{ if 1 then \"yes\" else \"no\" }}").unwrap_err();
+    assert!(matches!(err, TemplarError::TypeMismatch { ref expected, .. } if expected == "Boolean"));
+}
+
+#[test]
+fn division_promotes_to_float_instead_of_truncating() {
+    assert_eq!(render("{{ 7 / 2 }}").unwrap(), "3.5");
+}
+
+#[test]
+fn division_by_zero_is_an_explicit_error_not_a_panic() {
+    let err = render("{{ 1 / 0 }}").unwrap_err();
+    assert!(matches!(err, TemplarError::RenderFailure(_)));
+}
+
+#[test]
+fn math_functions_compute_real_values() {
+    assert_eq!(render("{{ sqrt(16) }}").unwrap(), "4");
+    assert_eq!(render("{{ 2 | pow(10) }}").unwrap(), "1024");
+}
+
+#[test]
+fn trig_and_rounding_functions_compute_real_values() {
+    assert_eq!(render("{{ sin(0) }}").unwrap(), "0");
+    assert_eq!(render("{{ cos(0) }}").unwrap(), "1");
+    assert_eq!(render("{{ abs(0 - 5) }}").unwrap(), "5");
+    assert_eq!(render("{{ floor(3.7) }}").unwrap(), "3");
+    assert_eq!(render("{{ ceil(3.2) }}").unwrap(), "4");
+    assert_eq!(render("{{ round(2.5) }}").unwrap(), "3");
+}
+
+#[test]
+fn min_and_max_compare_two_values() {
+    assert_eq!(render("{{ 3 | min(5) }}").unwrap(), "3");
+    assert_eq!(render("{{ 3 | max(5) }}").unwrap(), "5");
+}
+
+#[test]
+fn and_short_circuits_before_evaluating_the_right_side() {
+    // the right side is a type mismatch that would surface as an error if
+    // it were ever evaluated; `false && ...` must never reach it.
+    assert_eq!(render("{{ false && (1 + \"two\") }}").unwrap(), "false");
+}
+
+#[test]
+fn or_short_circuits_before_evaluating_the_right_side() {
+    assert_eq!(render("{{ true || (1 + \"two\") }}").unwrap(), "true");
+}
+
+#[test]
+fn sum_and_product_fold_a_sequence() {
+    assert_eq!(render("{{ sum([1, 2, 3]) }}").unwrap(), "6");
+    assert_eq!(render("{{ product([1, 2, 3, 4]) }}").unwrap(), "24");
+}
+
+#[test]
+fn seq_min_and_seq_max_pick_the_extreme_of_a_sequence() {
+    assert_eq!(render("{{ seq_min([3, 1, 2]) }}").unwrap(), "1");
+    assert_eq!(render("{{ seq_max([3, 1, 2]) }}").unwrap(), "3");
+}
+
+#[test]
+fn seq_min_on_an_empty_sequence_is_a_render_failure_not_a_panic() {
+    let err = render("{{ seq_min([]) }}").unwrap_err();
+    assert!(matches!(err, TemplarError::RenderFailure(_)));
+}
+
+#[test]
+fn reduce_exposes_the_running_accumulator_and_item() {
+    let rendered = render("{{ reduce(0, [1, 2, 3], acc + item) }}").unwrap();
+    assert_eq!(rendered, "6");
+}